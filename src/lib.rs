@@ -44,15 +44,50 @@
 //!
 //! ```
 
-use std::{collections::BTreeMap, pin::Pin, sync::Arc, time::Duration};
+use std::{
+    collections::{BTreeMap, VecDeque},
+    pin::Pin,
+    sync::Arc,
+    time::Duration,
+};
 
 use eyre::Report;
-use futures::{stream::FuturesUnordered, Future, StreamExt};
+use futures::{
+    future::{AbortHandle, Abortable, Aborted},
+    stream::FuturesUnordered,
+    Future, Stream, StreamExt,
+};
 
-/// A wrapper around a `Future`.
+/// A boxed, pinned racer future.
+type RaceFuture<T> = Pin<Box<dyn Future<Output = Result<T, Report>> + Send>>;
+
+/// Builds a fresh `RaceFuture` each time it's called, so a racer can be run
+/// more than once (see `run_heats`).
+type RaceFactory<T> = Arc<dyn Fn() -> RaceFuture<T> + Send + Sync>;
+
+/// A wrapper around a `Future`, built fresh each time it's raced so a racer
+/// can be run more than once (see `run_heats`).
 struct Racer<T> {
     name: String,
-    fut: Pin<Box<dyn Future<Output = Result<T, Report>> + Send + Sync>>,
+    factory: RaceFactory<T>,
+    /// Caps how many heats this racer can actually run, regardless of what
+    /// `run_heats` asks for. Single-shot racers added via `add_racer` can
+    /// only ever produce one future, so this is `Some(1)` for them.
+    max_heats: Option<usize>,
+    /// Overrides the `RaceTrack`'s global timeout for this racer alone.
+    timeout: Option<Duration>,
+}
+
+/// Aggregated timing statistics for a racer run across one or more heats.
+#[derive(Debug, Clone)]
+pub struct RaceStats {
+    pub name: String,
+    pub min: Duration,
+    pub max: Duration,
+    pub mean: Duration,
+    pub median: Duration,
+    pub runs: usize,
+    pub failures: usize,
 }
 
 /// The rank and disqualification status of an executed Racer.
@@ -61,6 +96,13 @@ pub struct RaceResult<T> {
     pub name: String,
     pub duration: Duration,
     pub disqualified: bool,
+    /// Set when the racer was still in flight or still queued after
+    /// `run_until_winners` reached its target and was cancelled (or never
+    /// started) rather than allowed to finish.
+    pub aborted: bool,
+    /// The timeout that was actually armed for this racer — the racer's own
+    /// override if it had one, otherwise the `RaceTrack`'s global timeout.
+    pub timeout: Duration,
     pub error: Option<Arc<Report>>,
     pub value: Option<T>,
 }
@@ -68,15 +110,19 @@ pub struct RaceResult<T> {
 /// Race a set of `Future`s and rank them.
 pub struct RaceTrack<T> {
     timeout: Duration,
+    max_concurrency: Option<usize>,
     racers: Vec<Racer<T>>,
     rankings: BTreeMap<usize, RaceResult<T>>,
+    heat_stats: BTreeMap<usize, RaceStats>,
 }
 
 impl<T> Default for RaceTrack<T> {
     fn default() -> Self {
         Self {
             timeout: Duration::from_secs(5),
+            max_concurrency: None,
             rankings: BTreeMap::new(),
+            heat_stats: BTreeMap::new(),
             racers: Vec::new(),
         }
     }
@@ -94,57 +140,301 @@ where
         }
     }
 
-    /// Add a `Future` to the `RaceTrack`.
+    /// Wrap a single-shot `Future` in a factory that yields it once and then
+    /// errors, since it can't be rebuilt for a second heat.
+    fn single_shot_factory<F>(fut: F) -> RaceFactory<T>
+    where
+        F: Future<Output = Result<T, Report>> + Send + 'static,
+    {
+        let fut = Arc::new(std::sync::Mutex::new(Some(fut)));
+        Arc::new(move || match fut.lock().unwrap().take() {
+            Some(fut) => Box::pin(fut) as RaceFuture<T>,
+            None => Box::pin(async { Err(eyre::eyre!("racer has already run its single heat")) }),
+        })
+    }
+
+    /// Add a single-shot `Future` to the `RaceTrack`. Single-shot counterpart
+    /// of `add_racer_factory` for racers that can only ever run once;
+    /// `run_heats` caps this racer at a single heat even if asked for more.
     pub fn add_racer<F>(&mut self, name: impl Into<String>, fut: F)
     where
-        F: Future<Output = Result<T, Report>> + Send + Sync + 'static,
+        F: Future<Output = Result<T, Report>> + Send + 'static,
+    {
+        self.racers.push(Racer {
+            name: name.into(),
+            factory: Self::single_shot_factory(fut),
+            max_heats: Some(1),
+            timeout: None,
+        });
+    }
+
+    /// Like `add_racer`, but arms a timeout for this racer alone instead of
+    /// the `RaceTrack`'s global one. Useful when some racers (e.g. a
+    /// cold-cache network call) are expected to be slower than others.
+    pub fn add_racer_with_timeout<F>(&mut self, name: impl Into<String>, fut: F, timeout: Duration)
+    where
+        F: Future<Output = Result<T, Report>> + Send + 'static,
+    {
+        self.racers.push(Racer {
+            name: name.into(),
+            factory: Self::single_shot_factory(fut),
+            max_heats: Some(1),
+            timeout: Some(timeout),
+        });
+    }
+
+    /// Add a racer built from a factory `Fn() -> Future` instead of a single
+    /// `Future`, so a fresh future can be produced for each heat in
+    /// `run_heats`.
+    pub fn add_racer_factory<F, Fut>(&mut self, name: impl Into<String>, factory: F)
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<T, Report>> + Send + 'static,
     {
         self.racers.push(Racer {
             name: name.into(),
-            fut: Box::pin(fut),
+            factory: Arc::new(move || Box::pin(factory())),
+            max_heats: None,
+            timeout: None,
+        });
+    }
+
+    /// Cap the number of racers running at once, the way a pit lane only has
+    /// so many lanes. Racers beyond the limit sit in a queue and don't start
+    /// (and don't have their `duration` clock start) until a lane frees up.
+    pub fn with_lane_width(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = Some(max_concurrency);
+        self
+    }
+
+    /// Spawn a single racer, timing it from the moment it actually starts
+    /// running rather than from when it was queued. `default_timeout` is
+    /// armed unless the racer carries its own override.
+    fn spawn_racer(
+        racer: Racer<T>,
+        default_timeout: Duration,
+    ) -> tokio::task::JoinHandle<RaceResult<T>> {
+        let name = racer.name;
+        let timeout = racer.timeout.unwrap_or(default_timeout);
+        let fut = (racer.factory)();
+        tokio::spawn(async move {
+            let start = std::time::Instant::now();
+            let res = tokio::time::timeout(timeout, fut).await;
+            let duration = start.elapsed();
+            let disqualified = res.is_err();
+
+            // Do some magic on the timeout error and then split the result!
+            let result = res.unwrap_or_else(|_| Err(eyre::eyre!("Racer timed out")));
+            let (value, error) = match result {
+                Ok(value) => (Some(value), None),
+                Err(error) => (None, Some(error)),
+            };
+
+            RaceResult {
+                name,
+                duration,
+                disqualified,
+                aborted: false,
+                timeout,
+                error: error.map(Arc::new),
+                value,
+            }
+        })
+    }
+
+    /// Spawn a racer wrapped in an `Abortable`, returning the join handle
+    /// alongside the `AbortHandle` needed to cancel it mid-race.
+    fn spawn_racer_abortable(
+        racer: Racer<T>,
+        default_timeout: Duration,
+    ) -> (tokio::task::JoinHandle<RaceResult<T>>, AbortHandle) {
+        let (abort_handle, abort_registration) = AbortHandle::new_pair();
+        let name = racer.name;
+        let timeout = racer.timeout.unwrap_or(default_timeout);
+        let fut = (racer.factory)();
+        let handle = tokio::spawn(async move {
+            let start = std::time::Instant::now();
+            let res = Abortable::new(tokio::time::timeout(timeout, fut), abort_registration).await;
+            let duration = start.elapsed();
+
+            match res {
+                Err(Aborted) => RaceResult {
+                    name,
+                    duration,
+                    disqualified: false,
+                    aborted: true,
+                    timeout,
+                    error: None,
+                    value: None,
+                },
+                Ok(res) => {
+                    let disqualified = res.is_err();
+
+                    // Do some magic on the timeout error and then split the result!
+                    let result = res.unwrap_or_else(|_| Err(eyre::eyre!("Racer timed out")));
+                    let (value, error) = match result {
+                        Ok(value) => (Some(value), None),
+                        Err(error) => (None, Some(error)),
+                    };
+
+                    RaceResult {
+                        name,
+                        duration,
+                        disqualified,
+                        aborted: false,
+                        timeout,
+                        error: error.map(Arc::new),
+                        value,
+                    }
+                }
+            }
         });
+        (handle, abort_handle)
     }
 
     /// Run the `RaceTrack` and collect the rankings.
     pub async fn run(&mut self) {
-        let racers = std::mem::take(&mut self.racers);
+        let mut pending: VecDeque<Racer<T>> = std::mem::take(&mut self.racers).into();
 
         // Clear the rankings from the previous run.
         self.rankings.clear();
 
-        // Run the racers.
+        // Fill the pit lane: spawn up to `max_concurrency` racers (or all of
+        // them, if unbounded) and keep the rest queued.
+        let lane_width = self.max_concurrency.unwrap_or(usize::MAX);
         let mut tasks = FuturesUnordered::new();
-        for racer in racers {
-            let name = racer.name.clone();
-            let timeout = self.timeout;
-            tasks.push(tokio::spawn(async move {
-                let start = std::time::Instant::now();
-                let res = tokio::time::timeout(timeout, racer.fut).await;
-                let duration = start.elapsed();
-                let disqualified = res.is_err();
-
-                // Do some magic on the timeout error and then split the result!
-                let result = res.unwrap_or_else(|_| Err(eyre::eyre!("Racer timed out")));
-                let (value, error) = match result {
-                    Ok(value) => (Some(value), None),
-                    Err(error) => (None, Some(error)),
-                };
-
-                RaceResult {
-                    name,
-                    duration,
-                    disqualified,
-                    error: error.map(Arc::new),
-                    value,
-                }
-            }));
+        for _ in 0..lane_width.min(pending.len()) {
+            let racer = pending.pop_front().expect("queue was just bounds-checked");
+            tasks.push(Self::spawn_racer(racer, self.timeout));
         }
 
-        // RaceResult em up!
+        // RaceResult em up! Every time a lane frees up, send the next queued
+        // racer out onto the track.
         let mut i = 0;
         while let Some(result) = tasks.next().await {
             self.rankings.insert(i, result.unwrap());
             i += 1;
+
+            if let Some(racer) = pending.pop_front() {
+                tasks.push(Self::spawn_racer(racer, self.timeout));
+            }
+        }
+    }
+
+    /// Run the `RaceTrack`, yielding each `RaceResult` the moment its racer
+    /// finishes instead of waiting for the whole race. Draining the stream
+    /// still populates `rankings()` as a side effect, so the collect-then-read
+    /// API keeps working once the stream is done.
+    pub fn run_stream(&mut self) -> Pin<Box<dyn Stream<Item = RaceResult<T>> + '_>> {
+        let mut pending: VecDeque<Racer<T>> = std::mem::take(&mut self.racers).into();
+
+        // Clear the rankings from the previous run.
+        self.rankings.clear();
+
+        let timeout = self.timeout;
+        let lane_width = self.max_concurrency.unwrap_or(usize::MAX);
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+
+        tokio::spawn(async move {
+            let mut tasks = FuturesUnordered::new();
+            for _ in 0..lane_width.min(pending.len()) {
+                if let Some(racer) = pending.pop_front() {
+                    tasks.push(Self::spawn_racer(racer, timeout));
+                }
+            }
+
+            while let Some(result) = tasks.next().await {
+                if tx.send(result.unwrap()).is_err() {
+                    break;
+                }
+
+                if let Some(racer) = pending.pop_front() {
+                    tasks.push(Self::spawn_racer(racer, timeout));
+                }
+            }
+        });
+
+        let mut i = 0;
+        Box::pin(
+            futures::stream::unfold(rx, |mut rx| async move { rx.recv().await.map(|r| (r, rx)) })
+                .map(move |result| {
+                    self.rankings.insert(i, result.clone());
+                    i += 1;
+                    result
+                }),
+        )
+    }
+
+    /// Run the `RaceTrack` and resolve as soon as `n` racers finish
+    /// successfully, aborting every racer still in flight instead of letting
+    /// it run to the timeout. If fewer than `n` racers ever succeed, this
+    /// falls back to the usual timeout behavior of `run`. Aborted racers are
+    /// still recorded, ranked after all finishers, with `value: None`.
+    pub async fn run_until_winners(&mut self, n: usize) {
+        let mut pending: VecDeque<Racer<T>> = std::mem::take(&mut self.racers).into();
+
+        // Clear the rankings from the previous run.
+        self.rankings.clear();
+
+        let lane_width = self.max_concurrency.unwrap_or(usize::MAX);
+        let mut tasks = FuturesUnordered::new();
+        let mut abort_handles = Vec::new();
+        for _ in 0..lane_width.min(pending.len()) {
+            let racer = pending.pop_front().expect("queue was just bounds-checked");
+            let (task, abort_handle) = Self::spawn_racer_abortable(racer, self.timeout);
+            abort_handles.push(abort_handle);
+            tasks.push(task);
+        }
+
+        let mut finishers = Vec::new();
+        let mut aborted = Vec::new();
+        let mut wins = 0;
+        let mut won = false;
+        while let Some(result) = tasks.next().await {
+            let result = result.unwrap();
+            if result.aborted {
+                aborted.push(result);
+            } else {
+                if !result.disqualified {
+                    wins += 1;
+                }
+                finishers.push(result);
+
+                if wins >= n && !won {
+                    won = true;
+                    for abort_handle in &abort_handles {
+                        abort_handle.abort();
+                    }
+                }
+            }
+
+            // Once the winner target is reached, stop sending queued racers
+            // out onto the track — they're handled below instead.
+            if !won {
+                if let Some(racer) = pending.pop_front() {
+                    let (task, abort_handle) = Self::spawn_racer_abortable(racer, self.timeout);
+                    abort_handles.push(abort_handle);
+                    tasks.push(task);
+                }
+            }
+        }
+
+        // Racers that were still queued when the target was reached never
+        // got a lane at all; record them as aborted rather than running them.
+        for racer in pending.drain(..) {
+            aborted.push(RaceResult {
+                name: racer.name,
+                duration: Duration::ZERO,
+                disqualified: false,
+                aborted: true,
+                timeout: racer.timeout.unwrap_or(self.timeout),
+                error: None,
+                value: None,
+            });
+        }
+
+        for (i, result) in finishers.into_iter().chain(aborted).enumerate() {
+            self.rankings.insert(i, result);
         }
     }
 
@@ -152,6 +442,94 @@ where
     pub fn rankings(&self) -> Vec<RaceResult<T>> {
         self.rankings.values().cloned().collect()
     }
+
+    /// Run every racer for `heats` heats, aggregating each racer's durations
+    /// into a `RaceStats`. Single-shot racers added via `add_racer` only ever
+    /// run once, no matter how many heats are requested.
+    pub async fn run_heats(&mut self, heats: usize) {
+        let racers = std::mem::take(&mut self.racers);
+        self.heat_stats.clear();
+
+        let mut tasks = FuturesUnordered::new();
+        for racer in racers {
+            let timeout = racer.timeout.unwrap_or(self.timeout);
+            let heats = racer.max_heats.map_or(heats, |cap| cap.min(heats)).max(1);
+            tasks.push(tokio::spawn(async move {
+                let mut durations = Vec::new();
+                let mut failures = 0;
+                for _ in 0..heats {
+                    let start = std::time::Instant::now();
+                    let res = tokio::time::timeout(timeout, (racer.factory)()).await;
+                    let duration = start.elapsed();
+                    match res {
+                        Ok(Ok(_)) => durations.push(duration),
+                        _ => failures += 1,
+                    }
+                }
+                Self::summarize_heats(racer.name, durations, failures, heats)
+            }));
+        }
+
+        let mut stats = Vec::new();
+        while let Some(result) = tasks.next().await {
+            stats.push(result.unwrap());
+        }
+
+        // Rank by mean ascending.
+        stats.sort_by_key(|stat| stat.mean);
+
+        for (i, stat) in stats.into_iter().enumerate() {
+            self.heat_stats.insert(i, stat);
+        }
+    }
+
+    /// Boil a racer's per-heat durations down into min/max/mean/median,
+    /// excluding failed heats from the timing numbers but still counting
+    /// them.
+    fn summarize_heats(
+        name: String,
+        mut durations: Vec<Duration>,
+        failures: usize,
+        heats: usize,
+    ) -> RaceStats {
+        durations.sort();
+
+        let runs = durations.len();
+        if runs == 0 {
+            return RaceStats {
+                name,
+                min: Duration::ZERO,
+                max: Duration::ZERO,
+                mean: Duration::ZERO,
+                median: Duration::ZERO,
+                runs: heats,
+                failures,
+            };
+        }
+
+        let mean = durations.iter().sum::<Duration>() / runs as u32;
+        let median = if runs.is_multiple_of(2) {
+            (durations[runs / 2 - 1] + durations[runs / 2]) / 2
+        } else {
+            durations[runs / 2]
+        };
+
+        RaceStats {
+            name,
+            min: durations[0],
+            max: durations[runs - 1],
+            mean,
+            median,
+            runs: heats,
+            failures,
+        }
+    }
+
+    /// Get the aggregated heat statistics from the previous `run_heats` call,
+    /// ranked by mean duration ascending.
+    pub fn stats(&self) -> Vec<RaceStats> {
+        self.heat_stats.values().cloned().collect()
+    }
 }
 
 #[cfg(test)]
@@ -206,4 +584,222 @@ mod tests {
         );
         assert_eq!(rankings[3].value, None);
     }
+
+    #[tokio::test]
+    async fn stay_in_your_lane() {
+        let mut race_track =
+            RaceTrack::disqualify_after(Duration::from_millis(100)).with_lane_width(2);
+
+        for i in 1..=4 {
+            race_track.add_racer(format!("Racer #{i}"), async move {
+                sleep(Duration::from_millis(10)).await;
+                Ok(i)
+            });
+        }
+
+        race_track.run().await;
+        let rankings = race_track.rankings();
+
+        // All four racers should still finish within the timeout even though
+        // only two lanes are ever in flight at once.
+        assert_eq!(rankings.len(), 4);
+        for result in &rankings {
+            assert_eq!(result.disqualified, false);
+            assert!(result.duration < Duration::from_millis(100));
+        }
+    }
+
+    #[tokio::test]
+    async fn checkered_flag_cuts_the_field_short() {
+        let mut race_track = RaceTrack::disqualify_after(Duration::from_millis(500));
+
+        race_track.add_racer("Racer #1", async move {
+            sleep(Duration::from_millis(5)).await;
+            Ok(1)
+        });
+        race_track.add_racer("Racer #2", async move {
+            sleep(Duration::from_millis(10)).await;
+            Ok(2)
+        });
+        race_track.add_racer("Racer #3", async move {
+            sleep(Duration::from_millis(300)).await;
+            Ok(3)
+        });
+
+        race_track.run_until_winners(2).await;
+        let rankings = race_track.rankings();
+
+        assert_eq!(rankings.len(), 3);
+        assert_eq!(rankings[0].name, "Racer #1");
+        assert_eq!(rankings[0].aborted, false);
+        assert_eq!(rankings[1].name, "Racer #2");
+        assert_eq!(rankings[1].aborted, false);
+
+        assert_eq!(rankings[2].name, "Racer #3");
+        assert_eq!(rankings[2].aborted, true);
+        assert_eq!(rankings[2].value, None);
+    }
+
+    #[tokio::test]
+    async fn checkered_flag_also_scratches_the_queue() {
+        let start = std::time::Instant::now();
+
+        let mut race_track =
+            RaceTrack::disqualify_after(Duration::from_millis(500)).with_lane_width(2);
+
+        race_track.add_racer("Racer #1", async move {
+            sleep(Duration::from_millis(5)).await;
+            Ok(1)
+        });
+        race_track.add_racer("Racer #2", async move {
+            sleep(Duration::from_millis(10)).await;
+            Ok(2)
+        });
+        race_track.add_racer("Racer #3 (aborted mid-race)", async move {
+            sleep(Duration::from_millis(300)).await;
+            Ok(3)
+        });
+        race_track.add_racer("Racer #4 (never starts)", async move {
+            sleep(Duration::from_millis(300)).await;
+            Ok(4)
+        });
+
+        race_track.run_until_winners(2).await;
+        let rankings = race_track.rankings();
+
+        // Racer #3 takes the lane Racer #1 frees and is aborted when Racer #2
+        // reaches the target; Racer #4 is still queued and never gets a lane.
+        // So this should resolve right after the first two finish, not after
+        // the 300ms racers run.
+        assert!(start.elapsed() < Duration::from_millis(100));
+
+        assert_eq!(rankings.len(), 4);
+        for result in &rankings[2..] {
+            assert_eq!(result.aborted, true);
+            assert_eq!(result.value, None);
+        }
+    }
+
+    #[tokio::test]
+    async fn photo_finish_as_it_happens() {
+        let mut race_track = RaceTrack::disqualify_after(Duration::from_millis(100));
+
+        race_track.add_racer("Racer #1", async move {
+            sleep(Duration::from_millis(5)).await;
+            Ok(1)
+        });
+        race_track.add_racer("Racer #2", async move {
+            sleep(Duration::from_millis(15)).await;
+            Ok(2)
+        });
+
+        let mut names = Vec::new();
+        {
+            let mut stream = race_track.run_stream();
+            while let Some(result) = stream.next().await {
+                names.push(result.name);
+            }
+        }
+
+        assert_eq!(names, vec!["Racer #1", "Racer #2"]);
+
+        let rankings = race_track.rankings();
+        assert_eq!(rankings.len(), 2);
+        assert_eq!(rankings[0].name, "Racer #1");
+        assert_eq!(rankings[1].name, "Racer #2");
+    }
+
+    #[tokio::test]
+    async fn practice_laps_then_the_main_event() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let mut race_track = RaceTrack::disqualify_after(Duration::from_millis(100));
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let calls_for_factory = calls.clone();
+        race_track.add_racer_factory("Racer #1", move || {
+            let calls = calls_for_factory.clone();
+            async move {
+                calls.fetch_add(1, Ordering::SeqCst);
+                sleep(Duration::from_millis(5)).await;
+                Ok(1)
+            }
+        });
+        race_track.add_racer("Racer #2 (single-shot)", async move {
+            sleep(Duration::from_millis(5)).await;
+            Ok(2)
+        });
+
+        race_track.run_heats(3).await;
+        let stats = race_track.stats();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert_eq!(stats.len(), 2);
+
+        let factory_stats = stats.iter().find(|s| s.name == "Racer #1").unwrap();
+        assert_eq!(factory_stats.runs, 3);
+        assert_eq!(factory_stats.failures, 0);
+
+        let single_shot_stats = stats
+            .iter()
+            .find(|s| s.name == "Racer #2 (single-shot)")
+            .unwrap();
+        assert_eq!(single_shot_stats.runs, 1);
+        assert_eq!(single_shot_stats.failures, 0);
+    }
+
+    #[tokio::test]
+    async fn send_but_not_sync_racers_are_welcome() {
+        use std::cell::RefCell;
+
+        let mut race_track = RaceTrack::disqualify_after(Duration::from_millis(100));
+
+        // `RefCell` is `Send` but not `Sync`; holding one across an `.await`
+        // used to be rejected by `add_racer`'s `Sync` bound.
+        race_track.add_racer("Racer #1", async move {
+            let cell = RefCell::new(0);
+            sleep(Duration::from_millis(5)).await;
+            *cell.borrow_mut() += 1;
+            let value = *cell.borrow();
+            Ok(value)
+        });
+
+        race_track.run().await;
+        let rankings = race_track.rankings();
+
+        assert_eq!(rankings[0].disqualified, false);
+        assert_eq!(rankings[0].value, Some(1));
+    }
+
+    #[tokio::test]
+    async fn slow_racers_get_their_own_deadline() {
+        let mut race_track = RaceTrack::disqualify_after(Duration::from_millis(20));
+
+        race_track.add_racer_with_timeout(
+            "Racer #1 (cold cache)",
+            async move {
+                sleep(Duration::from_millis(50)).await;
+                Ok(1)
+            },
+            Duration::from_millis(100),
+        );
+        race_track.add_racer("Racer #2", async move {
+            sleep(Duration::from_millis(50)).await;
+            Ok(2)
+        });
+
+        race_track.run().await;
+        let rankings = race_track.rankings();
+
+        let racer_1 = rankings
+            .iter()
+            .find(|r| r.name == "Racer #1 (cold cache)")
+            .unwrap();
+        assert_eq!(racer_1.disqualified, false);
+        assert_eq!(racer_1.timeout, Duration::from_millis(100));
+
+        let racer_2 = rankings.iter().find(|r| r.name == "Racer #2").unwrap();
+        assert_eq!(racer_2.disqualified, true);
+        assert_eq!(racer_2.timeout, Duration::from_millis(20));
+    }
 }